@@ -4,22 +4,118 @@ extern crate pomo;
 
 fn main() {
     use std::io;
+    use std::time::Duration;
 
     use termion::async_stdin;
     use termion::raw::IntoRawMode;
     use termion::screen::AlternateScreen;
 
     use pomo::cli;
+    use pomo::config::Config;
+    use pomo::daemon::{self, Command, Status};
+    use pomo::history;
     use pomo::parser;
-    use pomo::pomo::Pomodoro;
+    use pomo::pomo::{Pomodoro, PomodoroConfig};
 
+    let config = Config::load();
     let matches = cli::build_cli().get_matches();
-    let raw_time = matches.value_of("time").unwrap_or("25:00");
-    let time = parser::parse_time(raw_time).expect("Unable to parse time param");
+
+    if matches.subcommand_matches("stats").is_some() {
+        let stats = history::stats();
+        println!("Work sessions completed: {}", stats.work_sessions);
+        println!(
+            "Focused today:      {}",
+            history::format_duration(stats.focused_today)
+        );
+        println!(
+            "Focused this week:  {}",
+            history::format_duration(stats.focused_this_week)
+        );
+        if !stats.per_goal.is_empty() {
+            println!("\nBy goal:");
+            for (goal, duration) in &stats.per_goal {
+                let label = if goal.is_empty() { "(no goal)" } else { goal };
+                println!("  {:<20} {}", label, history::format_duration(*duration));
+            }
+        }
+        return;
+    }
+
+    let print_status = |result: io::Result<Status>| match result {
+        Ok(status) => println!(
+            "{} [{}] {:?} ({}s remaining)",
+            status.goal, status.phase, status.state, status.remaining_secs
+        ),
+        Err(err) => eprintln!("pomo: couldn't reach a running daemon: {}", err),
+    };
+    if matches.subcommand_matches("pause").is_some() {
+        print_status(daemon::send(&Command::Toggle));
+        return;
+    }
+    if matches.subcommand_matches("stop").is_some() {
+        print_status(daemon::send(&Command::Stop));
+        return;
+    }
+    if matches.subcommand_matches("status").is_some() {
+        print_status(daemon::send(&Command::Status));
+        return;
+    }
+
+    let parse_duration_arg = |s: &str| {
+        parser::parse_duration(s)
+            .unwrap_or_else(|e| panic!("Unable to parse time param '{}': {}", s, e))
+    };
+    let raw_work = matches.value_of("work").or(matches.value_of("time"));
+    let work_time = raw_work
+        .map(parse_duration_arg)
+        .unwrap_or_else(|| Duration::from_secs(config.work_time));
+    let short_break = matches
+        .value_of("short-break")
+        .map(parse_duration_arg)
+        .unwrap_or_else(|| Duration::from_secs(config.short_break));
+    let long_break = matches
+        .value_of("long-break")
+        .map(parse_duration_arg)
+        .unwrap_or_else(|| Duration::from_secs(config.long_break));
+    let rounds = matches
+        .value_of("rounds")
+        .map(|s| s.parse::<u32>().expect("Unable to parse rounds param"))
+        .unwrap_or(config.rounds);
+    let cycles = matches
+        .value_of("cycles")
+        .map(|s| s.parse::<u32>().expect("Unable to parse cycles param"));
     let name = matches.value_of("goal").unwrap_or("").to_string();
+    let notify = if matches.is_present("no-notify") {
+        false
+    } else {
+        matches.is_present("notify") || config.notify
+    };
+    let sound_file = matches
+        .value_of("sound")
+        .map(String::from)
+        .or_else(|| config.sound_file.clone());
+    let log_history = !matches.is_present("no-log");
+
+    let pomo_config = PomodoroConfig {
+        name,
+        work_time,
+        short_break,
+        long_break,
+        rounds,
+        notify,
+        sound_file,
+        bell_count: config.bell_count,
+        cycles,
+        log_history,
+    };
+
+    if matches.subcommand_matches("daemon").is_some() {
+        daemon::run(pomo_config).unwrap();
+        return;
+    }
 
     let stdout = io::stdout();
     let screen = AlternateScreen::from(stdout.lock().into_raw_mode().unwrap());
-    let mut pomo = Pomodoro::from_parts(async_stdin(), screen, name, time);
+    let mut pomo = Pomodoro::from_parts(async_stdin(), screen, pomo_config);
     pomo.run().unwrap();
 }
@@ -1,18 +1,139 @@
 extern crate chrono;
 extern crate clap;
+extern crate dirs;
+extern crate notify_rust;
+extern crate rodio;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 extern crate serde_json;
 extern crate termion;
+extern crate toml;
+
+pub mod audio {
+    use std::fs::File;
+    use std::io::BufReader;
+
+    /// Play `path` to completion on a short-lived output stream, returning
+    /// `false` (without panicking) if the file is missing or can't be
+    /// decoded, so callers can fall back to the terminal bell.
+    pub fn play(path: &str) -> bool {
+        let device = match rodio::default_output_device() {
+            Some(device) => device,
+            None => return false,
+        };
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(_) => return false,
+        };
+        let source = match rodio::Decoder::new(BufReader::new(file)) {
+            Ok(source) => source,
+            Err(_) => return false,
+        };
+        let sink = rodio::Sink::new(&device);
+        sink.append(source);
+        sink.sleep_until_end();
+        true
+    }
+}
+
+pub mod config {
+    use std::fs;
+    use std::path::PathBuf;
+
+    const CONFIG_DIR: &str = "pomo";
+    const CONFIG_FILE: &str = "settings.toml";
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Config {
+        pub work_time: u64,
+        pub short_break: u64,
+        pub long_break: u64,
+        pub rounds: u32,
+        pub sound_file: Option<String>,
+        pub bell_count: Option<u16>,
+        pub notify: bool,
+    }
+
+    impl Default for Config {
+        fn default() -> Config {
+            Config {
+                work_time: 25 * 60,
+                short_break: 5 * 60,
+                long_break: 15 * 60,
+                rounds: 4,
+                sound_file: None,
+                bell_count: None,
+                notify: false,
+            }
+        }
+    }
+
+    fn settings_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join(CONFIG_DIR).join(CONFIG_FILE))
+    }
+
+    impl Config {
+        /// Load settings from the user's `settings.toml`, falling back to
+        /// `Config::default()` when the file can't be found or fails to
+        /// parse, so a malformed config never keeps the timer from starting.
+        pub fn load() -> Config {
+            settings_path()
+                .and_then(|path| fs::read_to_string(path).ok())
+                .and_then(|raw| toml::from_str(&raw).ok())
+                .unwrap_or_default()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn default_matches_25_minute_work_time() {
+            let config = Config::default();
+            assert_eq!(config.work_time, 25 * 60);
+            assert_eq!(config.short_break, 5 * 60);
+            assert_eq!(config.long_break, 15 * 60);
+            assert_eq!(config.rounds, 4);
+            assert_eq!(config.sound_file, None);
+            assert_eq!(config.bell_count, None);
+            assert_eq!(config.notify, false);
+        }
+
+        #[test]
+        fn round_trips_through_toml() {
+            let config = Config {
+                work_time: 50 * 60,
+                short_break: 10 * 60,
+                long_break: 20 * 60,
+                rounds: 3,
+                sound_file: Some("/tmp/bell.ogg".to_owned()),
+                bell_count: Some(3),
+                notify: true,
+            };
+            let serialized = toml::to_string(&config).unwrap();
+            let deserialized: Config = toml::from_str(&serialized).unwrap();
+            assert_eq!(config, deserialized);
+        }
+
+        #[test]
+        fn round_trips_default_through_toml() {
+            let serialized = toml::to_string(&Config::default()).unwrap();
+            let deserialized: Config = toml::from_str(&serialized).unwrap();
+            assert_eq!(Config::default(), deserialized);
+        }
+    }
+}
 
 mod events {
     use std::time::{Duration, Instant};
 
-    use super::timer::State;
+    use super::timer::{Phase, State};
 
     #[derive(Debug)]
     struct Event {
+        phase: Phase,
         state: State,
         time: Instant,
     }
@@ -31,11 +152,12 @@ mod events {
             }
         }
 
-        pub fn log(&mut self, state: State) {
+        pub fn log(&mut self, phase: Phase, state: State) {
             let ref mut states = self.states;
             let len = states.len();
-            if len == 0 || states[len - 1].state != state {
+            if len == 0 || states[len - 1].state != state || states[len - 1].phase != phase {
                 states.push(Event {
+                    phase,
                     state,
                     time: Instant::now(),
                 })
@@ -47,8 +169,9 @@ mod events {
         }
     }
 
-    #[derive(Serialize)]
-    struct Span {
+    #[derive(Serialize, Deserialize)]
+    pub struct Span {
+        phase: Phase,
         state: State,
         duration: String,
     }
@@ -59,13 +182,26 @@ mod events {
             let secs = d.as_secs();
             let tenths = d.subsec_millis() / 100;
             Span {
+                phase: start.phase,
                 state: start.state,
                 duration: format!("{}.{}", secs, tenths),
             }
         }
+
+        pub fn phase(&self) -> Phase {
+            self.phase
+        }
+
+        pub fn state(&self) -> State {
+            self.state
+        }
+
+        pub fn seconds(&self) -> f64 {
+            self.duration.parse::<f64>().unwrap_or(0.0)
+        }
     }
 
-    #[derive(Serialize)]
+    #[derive(Serialize, Deserialize)]
     pub struct Formatted {
         title: String,
         events: Vec<Span>,
@@ -82,6 +218,285 @@ mod events {
                     .collect::<Vec<_>>(),
             }
         }
+
+        pub fn title(&self) -> &str {
+            &self.title
+        }
+
+        pub fn events(&self) -> &[Span] {
+            &self.events
+        }
+    }
+}
+
+pub mod notify {
+    use super::timer::Phase;
+
+    /// A sink for end-of-session alerts, independent of the terminal bell.
+    pub trait Notifier {
+        fn notify(&self, summary: &str, body: &str);
+    }
+
+    pub struct DesktopNotifier;
+
+    impl Notifier for DesktopNotifier {
+        fn notify(&self, summary: &str, body: &str) {
+            use notify_rust::Notification;
+
+            // A missing D-Bus session (e.g. headless CI) shouldn't crash the
+            // timer, so we log and move on rather than unwrap.
+            if let Err(err) = Notification::new().summary(summary).body(body).show() {
+                eprintln!("pomo: desktop notification failed: {}", err);
+            }
+        }
+    }
+
+    pub struct NullNotifier;
+
+    impl Notifier for NullNotifier {
+        fn notify(&self, _summary: &str, _body: &str) {}
+    }
+
+    /// Build the `Notifier` a run should use: a real `DesktopNotifier` when
+    /// notifications are enabled, otherwise a `NullNotifier` that no-ops.
+    /// Shared by the terminal UI and `daemon::run` so both modes agree on
+    /// what "notifications enabled" means.
+    pub fn notifier_for(enabled: bool) -> Box<Notifier> {
+        if enabled {
+            Box::new(DesktopNotifier)
+        } else {
+            Box::new(NullNotifier)
+        }
+    }
+
+    pub fn message_for(goal: &str, phase: Phase) -> (String, String) {
+        let summary = if goal.is_empty() {
+            "Pomodoro".to_owned()
+        } else {
+            goal.to_owned()
+        };
+        let body = match phase {
+            Phase::Work => "Work session complete, take a break".to_owned(),
+            Phase::ShortBreak | Phase::LongBreak => "Break's over, back to work".to_owned(),
+        };
+        (summary, body)
+    }
+}
+
+pub mod history {
+    use std::collections::HashMap;
+    use std::fs::{self, OpenOptions};
+    use std::io::{BufRead, BufReader, Write};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use chrono::{Date, DateTime, Datelike, Duration as OldDuration, Local, TimeZone};
+
+    use super::timer::Phase;
+
+    const HISTORY_DIR: &str = "pomo";
+    const HISTORY_FILE: &str = "history.jsonl";
+
+    /// One completed work/break interval: when it started, what it was
+    /// for, and how long it was planned to run vs. how long it actually
+    /// took (including any time spent paused).
+    #[derive(Serialize, Deserialize)]
+    struct Record {
+        started_at: i64,
+        title: String,
+        phase: Phase,
+        planned: String,
+        elapsed: String,
+    }
+
+    fn history_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join(HISTORY_DIR).join(HISTORY_FILE))
+    }
+
+    fn fmt_duration(d: Duration) -> String {
+        let secs = d.as_secs();
+        let tenths = d.subsec_millis() / 100;
+        format!("{}.{}", secs, tenths)
+    }
+
+    fn parse_duration(s: &str) -> Duration {
+        let secs = s.parse::<f64>().unwrap_or(0.0);
+        Duration::from_millis((secs * 1000.0) as u64)
+    }
+
+    /// Append one completed interval to the history file as soon as it
+    /// finishes, so a crash or forced quit mid-session only loses the
+    /// interval in progress rather than the whole day's history.
+    /// Gracefully no-ops if the file can't be written.
+    pub fn log_interval(start: DateTime<Local>, title: &str, phase: Phase, planned: Duration, elapsed: Duration) {
+        let path = match history_path() {
+            Some(path) => path,
+            None => return,
+        };
+        if let Some(parent) = path.parent() {
+            if fs::create_dir_all(parent).is_err() {
+                return;
+            }
+        }
+        let record = Record {
+            started_at: start.timestamp(),
+            title: title.to_owned(),
+            phase,
+            planned: fmt_duration(planned),
+            elapsed: fmt_duration(elapsed),
+        };
+        let line = match serde_json::to_string(&record) {
+            Ok(line) => line,
+            Err(_) => return,
+        };
+        if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(&path) {
+            let _ = writeln!(file, "{}", line);
+        }
+    }
+
+    /// Read back whatever history lines parse cleanly, silently skipping
+    /// partial/corrupt trailing lines so a crash mid-write doesn't poison
+    /// the whole file.
+    fn read_records() -> Vec<Record> {
+        let path = match history_path() {
+            Some(path) => path,
+            None => return vec![],
+        };
+        let file = match fs::File::open(&path) {
+            Ok(file) => file,
+            Err(_) => return vec![],
+        };
+        BufReader::new(file)
+            .lines()
+            .filter_map(|line| line.ok())
+            .filter_map(|line| serde_json::from_str::<Record>(&line).ok())
+            .collect()
+    }
+
+    /// Aggregate focus-time stats computed from the history file.
+    pub struct Stats {
+        pub work_sessions: u32,
+        pub focused_today: Duration,
+        pub focused_this_week: Duration,
+        pub per_goal: Vec<(String, Duration)>,
+    }
+
+    /// Format like "1h 40m", "45m", or "30s", whichever units are needed.
+    pub fn format_duration(d: Duration) -> String {
+        let total = d.as_secs();
+        let hours = total / 3600;
+        let minutes = (total % 3600) / 60;
+        let seconds = total % 60;
+        if hours > 0 {
+            format!("{}h {}m", hours, minutes)
+        } else if minutes > 0 {
+            format!("{}m", minutes)
+        } else {
+            format!("{}s", seconds)
+        }
+    }
+
+    /// Fold `records` into `Stats` relative to `today`/`week_start`, split
+    /// out from `stats()` so the date-window logic can be exercised with
+    /// hand-built `Record`s instead of the real history file.
+    fn aggregate(records: Vec<Record>, today: Date<Local>, week_start: Date<Local>) -> Stats {
+        let mut work_sessions = 0u32;
+        let mut focused_today = Duration::from_secs(0);
+        let mut focused_this_week = Duration::from_secs(0);
+        let mut per_goal: HashMap<String, Duration> = HashMap::new();
+
+        for record in records {
+            if record.phase != Phase::Work {
+                continue;
+            }
+            let started_date = Local.timestamp(record.started_at, 0).date();
+            work_sessions += 1;
+            let elapsed = parse_duration(&record.elapsed);
+            if started_date == today {
+                focused_today += elapsed;
+            }
+            if started_date >= week_start {
+                focused_this_week += elapsed;
+            }
+            *per_goal
+                .entry(record.title.clone())
+                .or_insert_with(|| Duration::from_secs(0)) += elapsed;
+        }
+
+        let mut per_goal: Vec<(String, Duration)> = per_goal.into_iter().collect();
+        per_goal.sort_by(|a, b| b.1.cmp(&a.1));
+
+        Stats {
+            work_sessions,
+            focused_today,
+            focused_this_week,
+            per_goal,
+        }
+    }
+
+    pub fn stats() -> Stats {
+        let now = Local::now();
+        let today = now.date();
+        let week_start = today - OldDuration::days(today.weekday().num_days_from_monday() as i64);
+        aggregate(read_records(), today, week_start)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn record(started_at: i64, title: &str, elapsed_secs: u64) -> Record {
+            Record {
+                started_at,
+                title: title.to_owned(),
+                phase: Phase::Work,
+                planned: fmt_duration(Duration::from_secs(elapsed_secs)),
+                elapsed: fmt_duration(Duration::from_secs(elapsed_secs)),
+            }
+        }
+
+        #[test]
+        fn counts_todays_record_in_today_and_this_week() {
+            let today = Local.ymd(2026, 7, 30);
+            let week_start = today - OldDuration::days(2);
+            let started = today.and_hms(9, 0, 0).timestamp();
+            let stats = aggregate(vec![record(started, "writing", 1500)], today, week_start);
+            assert_eq!(stats.work_sessions, 1);
+            assert_eq!(stats.focused_today, Duration::from_secs(1500));
+            assert_eq!(stats.focused_this_week, Duration::from_secs(1500));
+        }
+
+        #[test]
+        fn excludes_record_older_than_a_week_from_both_windows() {
+            let today = Local.ymd(2026, 7, 30);
+            let week_start = today - OldDuration::days(2);
+            let started = (today - OldDuration::days(10)).and_hms(9, 0, 0).timestamp();
+            let stats = aggregate(vec![record(started, "writing", 1500)], today, week_start);
+            // Still counted toward the all-time total...
+            assert_eq!(stats.work_sessions, 1);
+            // ...but not in either date window.
+            assert_eq!(stats.focused_today, Duration::from_secs(0));
+            assert_eq!(stats.focused_this_week, Duration::from_secs(0));
+        }
+
+        #[test]
+        fn sums_per_goal_across_multiple_records() {
+            let today = Local.ymd(2026, 7, 30);
+            let week_start = today - OldDuration::days(2);
+            let records = vec![
+                record(today.and_hms(9, 0, 0).timestamp(), "writing", 1500),
+                record(today.and_hms(14, 0, 0).timestamp(), "writing", 900),
+                record(today.and_hms(10, 0, 0).timestamp(), "reading", 600),
+            ];
+            let stats = aggregate(records, today, week_start);
+            assert_eq!(
+                stats.per_goal,
+                vec![
+                    ("writing".to_owned(), Duration::from_secs(2400)),
+                    ("reading".to_owned(), Duration::from_secs(600)),
+                ]
+            );
+        }
     }
 }
 
@@ -90,40 +505,189 @@ pub mod pomo {
     use std::thread::sleep;
     use std::time::Duration;
 
+    use chrono::{DateTime, Local};
     use termion::{clear, cursor, screen, style};
 
+    use super::audio;
     use super::card;
     use super::events::Logger;
     use super::help;
+    use super::history;
+    use super::notify::{self, Notifier};
     use super::timer;
-    use super::timer::Countdown;
+    use super::timer::{Countdown, Phase, State};
 
     type TermResult = Result<(), io::Error>;
 
     const FAREWELL_BELLS: u16 = 5;
     const SLEEP: Duration = Duration::from_millis(100);
 
-    pub struct Pomodoro<R, W> {
+    pub struct Session {
+        goal: String,
+        created_at: DateTime<Local>,
+        phase: Phase,
+        work_time: Duration,
+        short_break: Duration,
+        long_break: Duration,
+        rounds: u32,
+        round: u32,
         current: Countdown,
+    }
+
+    impl Session {
+        pub fn new(
+            goal: String,
+            work_time: Duration,
+            short_break: Duration,
+            long_break: Duration,
+            rounds: u32,
+        ) -> Session {
+            let round = 1;
+            let phase = Phase::Work;
+            let current = Countdown::new(work_time, &Session::title(&goal, phase, round, rounds), phase);
+            Session {
+                created_at: Local::now(),
+                goal,
+                phase,
+                work_time,
+                short_break,
+                long_break,
+                rounds,
+                round,
+                current,
+            }
+        }
+
+        fn title(goal: &str, phase: Phase, round: u32, rounds: u32) -> String {
+            let label = match phase {
+                Phase::Work => format!("{} {}/{}", phase.label(), round, rounds),
+                Phase::ShortBreak | Phase::LongBreak => phase.label().to_owned(),
+            };
+            if goal.is_empty() {
+                label
+            } else {
+                format!("{} \u{2014} {}", goal, label)
+            }
+        }
+
+        pub fn phase(&self) -> Phase {
+            self.phase
+        }
+
+        pub fn goal(&self) -> &str {
+            &self.goal
+        }
+
+        pub fn started_at(&self) -> DateTime<Local> {
+            self.created_at
+        }
+
+        pub fn current(&self) -> &Countdown {
+            &self.current
+        }
+
+        pub fn tick(&mut self) -> State {
+            self.current.tick()
+        }
+
+        pub fn toggle(&mut self) {
+            self.current.toggle()
+        }
+
+        pub fn finish(&mut self) {
+            self.current.finish()
+        }
+
+        /// Roll the session into its next phase, rebuilding a fresh
+        /// `Countdown` sized for that phase's duration.
+        pub fn advance(&mut self) {
+            let (phase, round) = match self.phase {
+                Phase::Work if self.round >= self.rounds => (Phase::LongBreak, self.round),
+                Phase::Work => (Phase::ShortBreak, self.round),
+                Phase::ShortBreak => (Phase::Work, self.round + 1),
+                Phase::LongBreak => (Phase::Work, 1),
+            };
+            let duration = match phase {
+                Phase::Work => self.work_time,
+                Phase::ShortBreak => self.short_break,
+                Phase::LongBreak => self.long_break,
+            };
+            self.phase = phase;
+            self.round = round;
+            self.current = Countdown::new(duration, &Session::title(&self.goal, phase, round, self.rounds), phase);
+        }
+    }
+
+    /// Behavior knobs for a run, layered on top of the `Session` timing
+    /// itself: how to alert the user and whether to persist history.
+    /// Shared by `Pomodoro` and `daemon::run` so the two drivers can't
+    /// drift apart as knobs keep getting added.
+    pub struct PomodoroOptions {
+        pub notifier: Box<Notifier>,
+        pub sound_file: Option<String>,
+        pub bell_count: Option<u16>,
+        pub cycles: Option<u32>,
+        pub log_history: bool,
+    }
+
+    /// Everything needed to start a fresh `Session` plus its
+    /// `PomodoroOptions`, gathered from the CLI/config in one place so
+    /// `Pomodoro::from_parts` and `daemon::run` take a single argument
+    /// instead of a long, easy-to-transpose parameter list.
+    pub struct PomodoroConfig {
+        pub name: String,
+        pub work_time: Duration,
+        pub short_break: Duration,
+        pub long_break: Duration,
+        pub rounds: u32,
+        pub notify: bool,
+        pub sound_file: Option<String>,
+        pub bell_count: Option<u16>,
+        pub cycles: Option<u32>,
+        pub log_history: bool,
+    }
+
+    impl PomodoroConfig {
+        /// Split into the `Session` it describes and the `PomodoroOptions`
+        /// that govern how a run behaves.
+        pub fn build(self) -> (Session, PomodoroOptions) {
+            let session = Session::new(self.name, self.work_time, self.short_break, self.long_break, self.rounds);
+            let options = PomodoroOptions {
+                notifier: notify::notifier_for(self.notify),
+                sound_file: self.sound_file,
+                bell_count: self.bell_count,
+                cycles: self.cycles,
+                log_history: self.log_history,
+            };
+            (session, options)
+        }
+    }
+
+    pub struct Pomodoro<R, W> {
+        session: Session,
         logger: Logger,
+        options: PomodoroOptions,
+        completed_cycles: u32,
         stdin: R,
         stdout: W,
     }
 
     impl<R: Read, W: Write> Pomodoro<R, W> {
-        pub fn new(stdin: R, stdout: W, counter: Countdown, logger: Logger) -> Pomodoro<R, W> {
+        pub fn new(stdin: R, stdout: W, session: Session, logger: Logger, options: PomodoroOptions) -> Pomodoro<R, W> {
             Pomodoro {
-                current: counter,
+                session,
                 logger,
+                options,
+                completed_cycles: 0,
                 stdin,
                 stdout,
             }
         }
 
-        pub fn from_parts(stdin: R, stdout: W, name: String, duration: Duration) -> Pomodoro<R, W> {
-            let counter = Countdown::new(duration, &name);
-            let logger = Logger::new(&name);
-            Pomodoro::new(stdin, stdout, counter, logger)
+        pub fn from_parts(stdin: R, stdout: W, config: PomodoroConfig) -> Pomodoro<R, W> {
+            let logger = Logger::new(&config.name);
+            let (session, options) = config.build();
+            Pomodoro::new(stdin, stdout, session, logger, options)
         }
 
         fn ring_once(&mut self) -> TermResult {
@@ -152,22 +716,63 @@ pub mod pomo {
             )?;
 
             // loop-and-a-half
+            let mut quitting = false;
             loop {
-                let curr_state = self.current.tick();
-                self.logger.log(curr_state);
+                let curr_state = self.session.tick();
+                self.logger.log(self.session.phase(), curr_state);
                 if curr_state == timer::State::Finished {
-                    break;
+                    // A `q`-forced finish is an abandoned interval, not a
+                    // completed one, so it shouldn't count toward history
+                    // or `pomo stats`.
+                    if self.options.log_history && !self.session.current().forced() {
+                        history::log_interval(
+                            self.session.current().start(),
+                            self.session.goal(),
+                            self.session.phase(),
+                            self.session.current().planned(),
+                            self.session.current().elapsed(),
+                        );
+                    }
+                    // Prefer the user's sound file over the terminal bell,
+                    // falling back to the bell if there's none configured
+                    // or it couldn't be played.
+                    let played = self
+                        .options
+                        .sound_file
+                        .as_ref()
+                        .map_or(false, |path| audio::play(path));
+                    if !played {
+                        let bell_count = self.options.bell_count.unwrap_or(FAREWELL_BELLS);
+                        self.ring(bell_count, SLEEP * 3)?;
+                    }
+                    let (summary, body) = notify::message_for(self.session.goal(), self.session.phase());
+                    self.options.notifier.notify(&summary, &body);
+                    if quitting {
+                        break;
+                    }
+                    // A full cycle is complete once its long break has
+                    // rung; once `--cycles` of them are done, stop here
+                    // rather than rolling into another work phase.
+                    if self.session.phase() == Phase::LongBreak {
+                        self.completed_cycles += 1;
+                        if self.options.cycles.map_or(false, |target| self.completed_cycles >= target) {
+                            break;
+                        }
+                    }
+                    self.session.advance();
+                    continue;
                 }
                 let mut key_bytes = [0];
                 self.stdin.read(&mut key_bytes)?;
 
                 match key_bytes[0] {
                     b'q' => {
-                        self.current.finish();
+                        self.session.finish();
+                        quitting = true;
                         continue;
                     }
                     b' ' => {
-                        self.current.toggle();
+                        self.session.toggle();
                         self.ring_once()?;
                     }
                     _ => {}
@@ -180,7 +785,7 @@ pub mod pomo {
                     width: 50,
                 };
                 let rendered_card = card::render(&card_dims);
-                let rendered = timer::render(&self.current, &card::Position { x: 5, y: 3 });
+                let rendered = timer::render(self.session.current(), &card::Position { x: 5, y: 3 });
                 let rendered_help = help::render(&card::Position { x: 5, y: 15 });
                 write!(self.stdout, "{}", rendered_card)?;
                 write!(self.stdout, "{}", rendered)?;
@@ -188,9 +793,6 @@ pub mod pomo {
                 self.stdout.flush()?;
                 sleep(SLEEP);
             }
-            if timer::State::Finished == self.current.tick() {
-                self.ring(FAREWELL_BELLS, SLEEP * 3)?;
-            }
 
             self.cleanup()?;
             Ok(())
@@ -207,41 +809,382 @@ pub mod pomo {
                 screen::ToMainScreen,
                 style::Reset
             )?;
-            let s = serde_json::to_string_pretty(&self.logger.format())?;
+            let formatted = self.logger.format();
+            let s = serde_json::to_string_pretty(&formatted)?;
             writeln!(self.stdout, "{}\r\n", s.replace("\n", "\r\n"),)
         }
     }
 }
 
+pub mod daemon {
+    use std::fs;
+    use std::io::{self, BufRead, BufReader, Write};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    use super::audio;
+    use super::history;
+    use super::notify;
+    use super::pomo::{PomodoroConfig, Session};
+    use super::timer::{Phase, State};
+
+    const SOCKET_DIR: &str = "pomo";
+    const SOCKET_FILE: &str = "daemon.sock";
+    const TICK: Duration = Duration::from_millis(100);
+
+    /// A command sent from a `pomo pause`/`stop`/`status` client to a
+    /// running `pomo daemon` over its Unix socket.
+    #[derive(Serialize, Deserialize)]
+    pub enum Command {
+        /// Maps onto `Session`'s underlying `Countdown::toggle`.
+        Toggle,
+        Stop,
+        Status,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct Status {
+        pub goal: String,
+        pub phase: String,
+        pub state: State,
+        pub remaining_secs: u64,
+    }
+
+    fn socket_path() -> Option<PathBuf> {
+        dirs::runtime_dir()
+            .or_else(dirs::data_dir)
+            .map(|dir| dir.join(SOCKET_DIR).join(SOCKET_FILE))
+    }
+
+    fn status_of(session: &Session) -> Status {
+        Status {
+            goal: session.goal().to_owned(),
+            phase: session.phase().label().to_owned(),
+            state: session.current().state(),
+            remaining_secs: session.current().remaining().as_secs(),
+        }
+    }
+
+    fn handle_client(stream: UnixStream, session: &Arc<Mutex<Session>>, stopping: &Arc<Mutex<bool>>) {
+        let mut writer = match stream.try_clone() {
+            Ok(writer) => writer,
+            Err(_) => return,
+        };
+        let mut line = String::new();
+        if BufReader::new(stream).read_line(&mut line).is_err() || line.trim().is_empty() {
+            return;
+        }
+        let command: Command = match serde_json::from_str(line.trim()) {
+            Ok(command) => command,
+            Err(_) => return,
+        };
+        let reply = match command {
+            Command::Toggle => {
+                session.lock().unwrap().toggle();
+                serde_json::to_string(&status_of(&session.lock().unwrap()))
+            }
+            Command::Stop => {
+                session.lock().unwrap().finish();
+                *stopping.lock().unwrap() = true;
+                serde_json::to_string(&status_of(&session.lock().unwrap()))
+            }
+            Command::Status => serde_json::to_string(&status_of(&session.lock().unwrap())),
+        };
+        if let Ok(reply) = reply {
+            let _ = writeln!(writer, "{}", reply);
+        }
+    }
+
+    /// Drive a `Session` headlessly, the same way `Pomodoro::run` drives
+    /// one in the terminal, while a background thread accepts `Command`s
+    /// from other shells over a Unix socket. Exits once a client sends
+    /// `Command::Stop` or the configured number of cycles is done, playing the configured sound/notification and
+    /// logging history the same way the terminal UI does (minus the
+    /// terminal bell, which has no tty to ring here).
+    pub fn run(config: PomodoroConfig) -> io::Result<()> {
+        let (session, options) = config.build();
+        let path = socket_path().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "no runtime or data directory available")
+        })?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // A live listener at `path` means another daemon already owns it;
+        // hijacking the socket out from under it would strand that process
+        // as an unreachable orphan. Only a dead daemon's stale socket file
+        // is safe to clean up and rebind.
+        if path.exists() {
+            if UnixStream::connect(&path).is_ok() {
+                return Err(io::Error::new(
+                    io::ErrorKind::AddrInUse,
+                    format!("a pomo daemon is already running on {}", path.display()),
+                ));
+            }
+            let _ = fs::remove_file(&path);
+        }
+        let listener = UnixListener::bind(&path)?;
+
+        let session = Arc::new(Mutex::new(session));
+        let stopping = Arc::new(Mutex::new(false));
+
+        {
+            let session = Arc::clone(&session);
+            let stopping = Arc::clone(&stopping);
+            thread::spawn(move || {
+                for incoming in listener.incoming() {
+                    if *stopping.lock().unwrap() {
+                        break;
+                    }
+                    if let Ok(stream) = incoming {
+                        handle_client(stream, &session, &stopping);
+                    }
+                }
+            });
+        }
+
+        let mut completed_cycles = 0u32;
+        loop {
+            let finished = session.lock().unwrap().tick() == State::Finished;
+            if finished {
+                let (start, goal, phase, planned, elapsed, forced) = {
+                    let session = session.lock().unwrap();
+                    (
+                        session.current().start(),
+                        session.goal().to_owned(),
+                        session.phase(),
+                        session.current().planned(),
+                        session.current().elapsed(),
+                        session.current().forced(),
+                    )
+                };
+                if options.log_history && !forced {
+                    history::log_interval(start, &goal, phase, planned, elapsed);
+                }
+                if let Some(sound_path) = options.sound_file.as_ref() {
+                    audio::play(sound_path);
+                }
+                let (summary, body) = notify::message_for(&goal, phase);
+                options.notifier.notify(&summary, &body);
+
+                if *stopping.lock().unwrap() {
+                    break;
+                }
+                let mut session = session.lock().unwrap();
+                if session.phase() == Phase::LongBreak {
+                    completed_cycles += 1;
+                    if options.cycles.map_or(false, |target| completed_cycles >= target) {
+                        break;
+                    }
+                }
+                session.advance();
+            }
+            if *stopping.lock().unwrap() {
+                break;
+            }
+            sleep(TICK);
+        }
+
+        let _ = fs::remove_file(&path);
+        Ok(())
+    }
+
+    /// Connect to a running daemon and send it a `Command`, returning the
+    /// `Status` it replies with.
+    pub fn send(command: &Command) -> io::Result<Status> {
+        let path = socket_path().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "no runtime or data directory available")
+        })?;
+        let mut stream = UnixStream::connect(&path)?;
+        let line = serde_json::to_string(command)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        writeln!(stream, "{}", line)?;
+
+        let mut reply = String::new();
+        BufReader::new(stream).read_line(&mut reply)?;
+        serde_json::from_str(reply.trim()).map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+    }
+}
+
 pub mod parser {
+    use std::fmt;
     use std::time::Duration;
 
-    fn parse_part(s: &str) -> Result<u64, ()> {
-        s.parse::<u64>().or(Err(()))
+    #[derive(Debug, PartialEq)]
+    pub enum ParseError {
+        /// A numeric component wasn't a valid unsigned integer.
+        InvalidNumber(String),
+        /// A `<integer><unit>` token used a unit other than `h`/`m`/`s`.
+        UnknownUnit(char),
+        /// The input didn't match `[[HH:]MM:]SS` or any `<integer><unit>` tokens.
+        InvalidFormat(String),
+        /// The total number of seconds didn't fit in a `u64`.
+        Overflow,
+    }
+
+    impl fmt::Display for ParseError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                ParseError::InvalidNumber(s) => write!(f, "'{}' is not a valid number", s),
+                ParseError::UnknownUnit(c) => write!(f, "'{}' is not a valid unit (expected h/m/s)", c),
+                ParseError::InvalidFormat(s) => write!(f, "'{}' is not a recognized duration", s),
+                ParseError::Overflow => write!(f, "duration is too large to represent"),
+            }
+        }
+    }
+
+    fn parse_part(s: &str) -> Result<u64, ParseError> {
+        s.parse::<u64>()
+            .map_err(|_| ParseError::InvalidNumber(s.to_owned()))
     }
 
-    pub fn parse_time(raw_time: &str) -> Result<Duration, ()> {
+    fn checked_total(parts: &[u64]) -> Result<u64, ParseError> {
+        let multipliers = [3600, 60, 1];
+        let offset = multipliers.len() - parts.len();
+        parts
+            .iter()
+            .enumerate()
+            .try_fold(0u64, |total, (i, part)| {
+                part.checked_mul(multipliers[offset + i])
+                    .and_then(|secs| total.checked_add(secs))
+                    .ok_or(ParseError::Overflow)
+            })
+    }
+
+    /// Parse a colon-separated `[[HH:]MM:]SS` duration.
+    pub fn parse_time(raw_time: &str) -> Result<Duration, ParseError> {
         let parts: Vec<_> = raw_time.split(":").collect();
-        // TODO: Handle overflow case without panicking
         match parts.len() {
-            p if p == 3 => {
-                let hours = parse_part(parts[0])?;
-                let minutes = parse_part(parts[1])?;
-                let seconds = parse_part(parts[2])?;
-                let total = hours * 3600 + minutes * 60 + seconds;
-                Ok(Duration::from_secs(total))
+            p if p == 1 || p == 2 || p == 3 => {
+                let parsed = parts
+                    .iter()
+                    .map(|p| parse_part(p))
+                    .collect::<Result<Vec<u64>, ParseError>>()?;
+                Ok(Duration::from_secs(checked_total(&parsed)?))
+            }
+            _ => Err(ParseError::InvalidFormat(raw_time.to_owned())),
+        }
+    }
+
+    /// Parse a sequence of `<integer><unit>` tokens (`h`/`m`/`s`), e.g.
+    /// `1h30m`, `90s`, or `1h 30m 10s` with whitespace between tokens.
+    /// Rejects unknown units and empty input.
+    fn parse_units(raw: &str) -> Result<Duration, ParseError> {
+        if raw.is_empty() {
+            return Err(ParseError::InvalidFormat(raw.to_owned()));
+        }
+        let mut total = 0u64;
+        let mut digits = String::new();
+        let mut saw_unit = false;
+        for c in raw.chars() {
+            if c.is_ascii_digit() {
+                digits.push(c);
+                continue;
             }
-            p if p == 2 => {
-                let minutes = parse_part(parts[0])?;
-                let seconds = parse_part(parts[1])?;
-                let total = minutes * 60 + seconds;
-                Ok(Duration::from_secs(total))
+            if c.is_whitespace() && digits.is_empty() {
+                continue;
             }
-            p if p == 1 => {
-                let seconds = parse_part(parts[0])?;
-                Ok(Duration::from_secs(seconds))
+            if digits.is_empty() {
+                return Err(ParseError::InvalidFormat(raw.to_owned()));
             }
-            _ => Err(()),
+            let amount = parse_part(&digits)?;
+            digits.clear();
+            let multiplier = match c {
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                other => return Err(ParseError::UnknownUnit(other)),
+            };
+            let secs = amount
+                .checked_mul(multiplier)
+                .ok_or(ParseError::Overflow)?;
+            total = total.checked_add(secs).ok_or(ParseError::Overflow)?;
+            saw_unit = true;
+        }
+        if !digits.is_empty() || !saw_unit {
+            return Err(ParseError::InvalidFormat(raw.to_owned()));
+        }
+        Ok(Duration::from_secs(total))
+    }
+
+    /// Parse either a colon-separated `[[HH:]MM:]SS` duration or a
+    /// humantime-style duration like `25m`/`1h30m`/`90s`.
+    pub fn parse_duration(raw: &str) -> Result<Duration, ParseError> {
+        // `:` is only meaningful to the colon format, so leftover/garbled
+        // colon input (e.g. "1:2:3:4") should report that format's own
+        // error rather than being misread by parse_units as a digit
+        // followed by a stray `:` "unit".
+        if raw.contains(':') {
+            return parse_time(raw);
+        }
+        parse_time(raw).or_else(|_| parse_units(raw))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn parse_time_handles_colon_formats() {
+            assert_eq!(parse_time("30"), Ok(Duration::from_secs(30)));
+            assert_eq!(parse_time("5:30"), Ok(Duration::from_secs(5 * 60 + 30)));
+            assert_eq!(parse_time("1:05:30"), Ok(Duration::from_secs(3600 + 5 * 60 + 30)));
+        }
+
+        #[test]
+        fn parse_time_rejects_too_many_colon_segments() {
+            assert_eq!(
+                parse_time("1:2:3:4"),
+                Err(ParseError::InvalidFormat("1:2:3:4".to_owned()))
+            );
+        }
+
+        #[test]
+        fn parse_time_overflows_on_huge_hours() {
+            assert_eq!(
+                parse_time("9999999999999999:00:00"),
+                Err(ParseError::Overflow)
+            );
+        }
+
+        #[test]
+        fn parse_duration_accepts_humantime_units() {
+            assert_eq!(parse_duration("25m"), Ok(Duration::from_secs(25 * 60)));
+            assert_eq!(parse_duration("1h30m"), Ok(Duration::from_secs(3600 + 30 * 60)));
+            assert_eq!(parse_duration("90s"), Ok(Duration::from_secs(90)));
+            assert_eq!(
+                parse_duration("1h 30m 10s"),
+                Ok(Duration::from_secs(3600 + 30 * 60 + 10))
+            );
+        }
+
+        #[test]
+        fn parse_duration_accepts_colon_formats() {
+            assert_eq!(parse_duration("25:00"), Ok(Duration::from_secs(25 * 60)));
+        }
+
+        #[test]
+        fn parse_duration_rejects_unknown_unit() {
+            assert_eq!(parse_duration("10x"), Err(ParseError::UnknownUnit('x')));
+        }
+
+        #[test]
+        fn parse_duration_overflows_on_huge_unit_value() {
+            assert_eq!(
+                parse_duration("9999999999999999h"),
+                Err(ParseError::Overflow)
+            );
+        }
+
+        #[test]
+        fn parse_duration_reports_invalid_format_for_colon_like_garbage() {
+            assert_eq!(
+                parse_duration("1:2:3:4"),
+                Err(ParseError::InvalidFormat("1:2:3:4".to_owned()))
+            );
         }
     }
 }
@@ -389,13 +1332,30 @@ mod timer {
     const SEC_IN_MINUTE: u64 = 60;
     const SEC_IN_HOUR: u64 = SEC_IN_MINUTE * 60;
 
-    #[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+    #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
     pub enum State {
         Running,
         Paused,
         Finished,
     }
 
+    #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum Phase {
+        Work,
+        ShortBreak,
+        LongBreak,
+    }
+
+    impl Phase {
+        pub fn label(&self) -> &'static str {
+            match self {
+                Phase::Work => "Work",
+                Phase::ShortBreak => "Short Break",
+                Phase::LongBreak => "Long Break",
+            }
+        }
+    }
+
     pub struct Countdown {
         state: State,
         start: DateTime<Local>,
@@ -403,10 +1363,12 @@ mod timer {
         running: Duration,
         paused: Duration,
         title: String,
+        phase: Phase,
+        forced: bool,
     }
 
     impl Countdown {
-        pub fn new(duration: Duration, title: &str) -> Countdown {
+        pub fn new(duration: Duration, title: &str, phase: Phase) -> Countdown {
             Countdown {
                 state: State::Running,
                 start: Local::now(),
@@ -414,9 +1376,15 @@ mod timer {
                 running: Duration::from_secs(0),
                 paused: Duration::from_secs(0),
                 title: String::from(title),
+                phase,
+                forced: false,
             }
         }
 
+        pub fn phase(&self) -> Phase {
+            self.phase
+        }
+
         pub fn tick(&mut self) -> State {
             use self::State::*;
             let diff = Local::now().signed_duration_since(self.start);
@@ -445,14 +1413,48 @@ mod timer {
             };
         }
 
+        /// Force this countdown to `Finished` before its duration has
+        /// elapsed, e.g. when the user quits mid-interval. Marks it as
+        /// `forced` so callers can skip treating it as a completed interval.
         pub fn finish(&mut self) {
             self.state = State::Finished;
+            self.forced = true;
+        }
+
+        /// Whether this countdown was cut short via `finish()` rather than
+        /// running its duration out naturally via `tick()`.
+        pub fn forced(&self) -> bool {
+            self.forced
+        }
+
+        pub fn start(&self) -> DateTime<Local> {
+            self.start
+        }
+
+        pub fn planned(&self) -> Duration {
+            self.duration
+        }
+
+        /// Total wall-clock time spent on this countdown, running time
+        /// plus however long it was paused.
+        pub fn elapsed(&self) -> Duration {
+            self.running + self.paused
+        }
+
+        pub fn state(&self) -> State {
+            self.state
+        }
+
+        pub fn remaining(&self) -> Duration {
+            self.duration
+                .checked_sub(self.running)
+                .unwrap_or_else(|| Duration::from_secs(0))
         }
     }
 
     impl Default for Countdown {
         fn default() -> Countdown {
-            Countdown::new(Duration::from_secs(0), "")
+            Countdown::new(Duration::from_secs(0), "", Phase::Work)
         }
     }
 
@@ -561,6 +1563,28 @@ mod timer {
         }
     }
 
+    /// The wall-clock time this phase will end, recomputed off the live
+    /// remaining duration (rather than a value cached at phase start) so
+    /// it slides forward while paused instead of going stale.
+    fn projected_end(countdown: &Countdown) -> Option<DateTime<Local>> {
+        let remaining = countdown.duration.checked_sub(countdown.running)?;
+        let remaining = OldDuration::from_std(remaining).ok()?;
+        Some(Local::now() + remaining)
+    }
+
+    fn rendered_eta(countdown: &Countdown) -> String {
+        match projected_end(countdown) {
+            Some(end) => {
+                let verb = match countdown.phase {
+                    Phase::Work => "next break",
+                    Phase::ShortBreak | Phase::LongBreak => "back to work",
+                };
+                format!("{} at {}", verb, end.format("%H:%M"))
+            }
+            None => "".to_owned(),
+        }
+    }
+
     pub fn render(countdown: &Countdown, pos: &Position) -> String {
         let rendered_title = format!(
             "{under}{bold}{title}",
@@ -575,6 +1599,12 @@ mod timer {
                 _ => "",
             }
         );
+        let rendered_eta = format!(
+            "{pos}{reset}{eta}",
+            pos = cursor::Goto(pos.x, pos.y + 1),
+            reset = style::Reset,
+            eta = rendered_eta(countdown)
+        );
         let mut lines: Vec<Vec<String>> = (4..9)
             .map(|idx| vec![format!("{}", cursor::Goto(pos.x, pos.y + idx))])
             .collect();
@@ -591,18 +1621,19 @@ mod timer {
             .collect::<Vec<String>>()
             .join("");
         format!(
-            "{pos}{reset}{title}{reset} {status}{lines}",
+            "{pos}{reset}{title}{reset} {status}{eta}{lines}",
             pos = cursor::Goto(pos.x, pos.y),
             reset = style::Reset,
             title = rendered_title,
             status = rendered_status,
+            eta = rendered_eta,
             lines = lines_str
         )
     }
 }
 
 pub mod cli {
-    use clap::{App, Arg};
+    use clap::{App, Arg, SubCommand};
 
     pub fn build_cli<'a, 'b>() -> clap::App<'a, 'b> {
         App::new("Pomo")
@@ -614,6 +1645,7 @@ pub mod cli {
                     .long("goal")
                     .short("g")
                     .value_name("NAME")
+                    .global(true)
                     .help(
                         "Name of the current task you are working on.
 (default: \"\")
@@ -625,12 +1657,140 @@ pub mod cli {
                     .long("time")
                     .short("t")
                     .value_name("TIME")
+                    .global(true)
                     .help(
-                        "Initial time (format: [[HH:]MM:]SS).
+                        "Initial time (format: [[HH:]MM:]SS or e.g. 25m, 1h30m).
 (default: 25:00 minutes)
 
 ",
                     ),
+            ).arg(
+                Arg::with_name("work")
+                    .long("work")
+                    .short("w")
+                    .value_name("TIME")
+                    .global(true)
+                    .help(
+                        "Length of a work interval (format: [[HH:]MM:]SS or e.g. 25m).
+(default: 25:00 minutes, falls back to --time if given)
+
+",
+                    ),
+            ).arg(
+                Arg::with_name("short-break")
+                    .long("short-break")
+                    .short("s")
+                    .value_name("TIME")
+                    .global(true)
+                    .help(
+                        "Length of a short break, taken after each work interval
+except the one before a long break (format: [[HH:]MM:]SS or e.g. 5m).
+(default: 5:00 minutes)
+
+",
+                    ),
+            ).arg(
+                Arg::with_name("long-break")
+                    .long("long-break")
+                    .short("l")
+                    .value_name("TIME")
+                    .global(true)
+                    .help(
+                        "Length of the long break, taken every --rounds work
+intervals (format: [[HH:]MM:]SS or e.g. 15m).
+(default: 15:00 minutes)
+
+",
+                    ),
+            ).arg(
+                Arg::with_name("rounds")
+                    .long("rounds")
+                    .short("r")
+                    .value_name("N")
+                    .global(true)
+                    .help(
+                        "Number of work intervals between long breaks.
+(default: 4)
+
+",
+                    ),
+            ).arg(
+                Arg::with_name("cycles")
+                    .long("cycles")
+                    .value_name("N")
+                    .global(true)
+                    .help(
+                        "Stop automatically after N full work/break cycles,
+each ending in a long break.
+(default: run indefinitely until 'q')
+
+",
+                    ),
+            ).arg(
+                Arg::with_name("notify")
+                    .long("notify")
+                    .conflicts_with("no-notify")
+                    .global(true)
+                    .help(
+                        "Send a desktop notification at the end of each
+work/break interval, in addition to the terminal bell.
+(default: on if set in settings.toml, otherwise off)
+
+",
+                    ),
+            ).arg(
+                Arg::with_name("no-notify")
+                    .long("no-notify")
+                    .conflicts_with("notify")
+                    .global(true)
+                    .help(
+                        "Disable desktop notifications for this run, even if
+settings.toml turns them on. Useful on headless/TTY-only
+machines without a notification daemon.
+
+",
+                    ),
+            ).arg(
+                Arg::with_name("sound")
+                    .long("sound")
+                    .value_name("PATH")
+                    .global(true)
+                    .help(
+                        "Audio file to play at the end of each work/break
+interval, instead of the terminal bell. The bell still rings
+if the file can't be played.
+
+",
+                    ),
+            ).arg(
+                Arg::with_name("no-log")
+                    .long("no-log")
+                    .global(true)
+                    .help(
+                        "Don't persist completed intervals to the session
+history file, so this run won't show up in `pomo stats`.
+
+",
+                    ),
+            ).subcommand(
+                SubCommand::with_name("stats").about(
+                    "Print aggregate focus time from session history \
+                     (today, this week, and per-goal breakdowns).",
+                ),
+            ).subcommand(
+                SubCommand::with_name("daemon").about(
+                    "Run the timer in the background, controllable from \
+                     other shells over a Unix socket, instead of the \
+                     terminal UI.",
+                ),
+            ).subcommand(
+                SubCommand::with_name("pause")
+                    .about("Toggle pause/resume on a running `pomo daemon`."),
+            ).subcommand(
+                SubCommand::with_name("stop").about("Stop a running `pomo daemon`."),
+            ).subcommand(
+                SubCommand::with_name("status")
+                    .about("Print a running `pomo daemon`'s current state."),
             )
     }
 }